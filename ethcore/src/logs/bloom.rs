@@ -0,0 +1,67 @@
+// Copyright 2015-2017 Parity Technologies (UK) Ltd.
+// This file is part of Parity.
+
+// Parity is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity.  If not, see <http://www.gnu.org/licenses/>.
+
+use bloomchain::group::BloomGroup;
+use ethbloom::Bloom;
+
+/// Helper structure representing the combined receipt bloom of a single block.
+#[derive(Clone)]
+pub struct BlockReceiptsBloom(Bloom);
+
+impl From<Bloom> for BlockReceiptsBloom {
+	fn from(bloom: Bloom) -> BlockReceiptsBloom {
+		let bytes: [u8; 256] = bloom.into();
+		BlockReceiptsBloom(Bloom::from(bytes))
+	}
+}
+
+impl Into<Bloom> for BlockReceiptsBloom {
+	fn into(self) -> Bloom {
+		self.0
+	}
+}
+
+/// Represents a group of `elements_per_index` consecutive receipt blooms.
+#[derive(Clone)]
+pub struct BlockReceiptsBloomGroup {
+	blooms: Vec<BlockReceiptsBloom>,
+}
+
+impl From<BloomGroup> for BlockReceiptsBloomGroup {
+	fn from(group: BloomGroup) -> Self {
+		let blooms = group.blooms
+			.into_iter()
+			.map(From::from)
+			.collect();
+
+		BlockReceiptsBloomGroup {
+			blooms: blooms
+		}
+	}
+}
+
+impl Into<BloomGroup> for BlockReceiptsBloomGroup {
+	fn into(self) -> BloomGroup {
+		let blooms = self.blooms
+			.into_iter()
+			.map(Into::into)
+			.collect();
+
+		BloomGroup {
+			blooms: blooms
+		}
+	}
+}
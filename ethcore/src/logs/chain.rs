@@ -0,0 +1,188 @@
+// Copyright 2015-2017 Parity Technologies (UK) Ltd.
+// This file is part of Parity.
+
+// Parity is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Hierarchical log-bloom index over per-block receipt blooms.
+//!
+//! This is the same hierarchical group-of-blooms problem `ethcore::trace` already solves for
+//! trace blooms (see `trace::bloom`), so the level/group math itself is delegated to
+//! `bloomchain::group::BloomGroupChain` rather than re-implemented here. This module only
+//! adapts our own `LogBloomStore` to the `BloomGroupDatabase` trait the chain needs, and
+//! converts `TraceGroupPosition`/`BlockReceiptsBloomGroup` at the boundary.
+
+use bloomchain::Config as BloomConfig;
+use bloomchain::group::{BloomGroup, BloomGroupChain, BloomGroupDatabase, GroupPosition};
+use ethbloom::Bloom;
+
+use BlockNumber;
+use trace::bloom::TraceGroupPosition;
+use super::bloom::BlockReceiptsBloomGroup;
+
+/// Storage backend for the log-bloom index.
+pub trait LogBloomStore {
+	/// Load a previously stored bloom group, if any.
+	fn load(&self, position: &TraceGroupPosition) -> Option<BlockReceiptsBloomGroup>;
+	/// Persist a bloom group.
+	fn save(&mut self, position: TraceGroupPosition, group: BlockReceiptsBloomGroup);
+}
+
+/// Adapts a `LogBloomStore` to the read-only `BloomGroupDatabase` the chain needs to plan
+/// inserts and filter queries.
+struct StoreDatabase<'a, S: 'a>(&'a S);
+
+impl<'a, S: LogBloomStore> BloomGroupDatabase for StoreDatabase<'a, S> {
+	fn blooms_at(&self, position: &GroupPosition) -> Option<BloomGroup> {
+		self.0.load(&TraceGroupPosition::from(position.clone())).map(Into::into)
+	}
+}
+
+/// Builds and queries the hierarchical log-bloom index.
+pub struct LogBloomChain {
+	config: BloomConfig,
+}
+
+impl LogBloomChain {
+	/// Create a new index with the given branching factor and number of levels.
+	pub fn new(elements_per_index: usize, levels: usize) -> Self {
+		LogBloomChain {
+			config: BloomConfig {
+				elements_per_index: elements_per_index,
+				levels: levels,
+			},
+		}
+	}
+
+	/// Insert the combined receipt bloom for `block_number`. `BloomGroupChain::insert` ORs
+	/// `bloom` into the block's group at every level; we just persist what it returns. Bits
+	/// already stored for the block are kept, so use `rollback` to replace a block's bloom.
+	pub fn insert<S: LogBloomStore>(&self, store: &mut S, block_number: BlockNumber, bloom: Bloom) {
+		let modified = {
+			let chain = BloomGroupChain::new(self.config, &StoreDatabase(store));
+			chain.insert(block_number as usize, bloom)
+		};
+
+		for (position, group) in modified {
+			store.save(TraceGroupPosition::from(position), BlockReceiptsBloomGroup::from(group));
+		}
+	}
+
+	/// Replace the stored bloom of a rolled-back block with its new (possibly zero) bloom and
+	/// recompute every parent group bottom-up from its children.
+	pub fn rollback<S: LogBloomStore>(&self, store: &mut S, block_number: BlockNumber, bloom: Bloom) {
+		let modified = {
+			let chain = BloomGroupChain::new(self.config, &StoreDatabase(store));
+			let number = block_number as usize;
+			chain.replace(&(number..number), vec![bloom])
+		};
+
+		for (position, group) in modified {
+			store.save(TraceGroupPosition::from(position), BlockReceiptsBloomGroup::from(group));
+		}
+	}
+
+	/// Find block numbers in `[from_block, to_block]` whose receipt bloom may contain
+	/// `bloom`. The result is a pruned candidate set — callers must still confirm matches
+	/// against the block's actual logs.
+	pub fn blocks_with_bloom<S: LogBloomStore>(&self, store: &S, bloom: &Bloom, from_block: BlockNumber, to_block: BlockNumber) -> Vec<BlockNumber> {
+		let chain = BloomGroupChain::new(self.config, &StoreDatabase(store));
+		chain.filter(from_block as usize, to_block as usize, bloom)
+			.into_iter()
+			.map(|n| n as BlockNumber)
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::collections::HashMap;
+	use ethbloom::{Bloom, Input};
+	use trace::bloom::TraceGroupPosition;
+	use super::super::bloom::BlockReceiptsBloomGroup;
+	use super::{LogBloomChain, LogBloomStore};
+
+	#[derive(Default)]
+	struct MemoryStore(HashMap<(u8, u32), BlockReceiptsBloomGroup>);
+
+	impl LogBloomStore for MemoryStore {
+		fn load(&self, position: &TraceGroupPosition) -> Option<BlockReceiptsBloomGroup> {
+			self.0.get(&(position.level, position.index)).cloned()
+		}
+
+		fn save(&mut self, position: TraceGroupPosition, group: BlockReceiptsBloomGroup) {
+			self.0.insert((position.level, position.index), group);
+		}
+	}
+
+	fn bloom_of(byte: u8) -> Bloom {
+		let mut bloom = Bloom::default();
+		bloom.accrue(Input::Raw(&[byte]));
+		bloom
+	}
+
+	#[test]
+	fn finds_inserted_block() {
+		let chain = LogBloomChain::new(4, 3);
+		let mut store = MemoryStore::default();
+
+		for n in 0..20u64 {
+			chain.insert(&mut store, n, bloom_of(n as u8));
+		}
+
+		let query = bloom_of(7);
+		let found = chain.blocks_with_bloom(&store, &query, 0, 19);
+		assert!(found.contains(&7));
+	}
+
+	#[test]
+	fn prunes_non_matching_range() {
+		let chain = LogBloomChain::new(4, 3);
+		let mut store = MemoryStore::default();
+
+		for n in 0..20u64 {
+			chain.insert(&mut store, n, bloom_of(n as u8));
+		}
+
+		let query = bloom_of(255);
+		let found = chain.blocks_with_bloom(&store, &query, 0, 19);
+		assert!(found.is_empty());
+	}
+
+	#[test]
+	fn rollback_updates_every_affected_level() {
+		let chain = LogBloomChain::new(4, 3);
+		let mut store = MemoryStore::default();
+
+		for n in 0..20u64 {
+			chain.insert(&mut store, n, bloom_of(n as u8));
+		}
+
+		let original_query = bloom_of(7);
+		assert!(chain.blocks_with_bloom(&store, &original_query, 0, 19).contains(&7));
+
+		// Roll block 7 back to a different bloom; the old query should no longer find it,
+		// and every level-0/1/2 group that combined block 7's bloom must have been
+		// recomputed to drop it, not just the leaf.
+		let replacement_query = bloom_of(42);
+		chain.rollback(&mut store, 7, bloom_of(42));
+
+		assert!(!chain.blocks_with_bloom(&store, &original_query, 0, 19).contains(&7));
+		assert!(chain.blocks_with_bloom(&store, &replacement_query, 0, 19).contains(&7));
+
+		// Rolling back to the zero bloom must remove block 7 from every group it used to
+		// match, proving parents were recomputed bottom-up rather than left stale.
+		chain.rollback(&mut store, 7, Bloom::default());
+		assert!(!chain.blocks_with_bloom(&store, &replacement_query, 0, 19).contains(&7));
+	}
+}
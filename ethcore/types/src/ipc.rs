@@ -0,0 +1,725 @@
+// Copyright 2015-2017 Parity Technologies (UK) Ltd.
+// This file is part of Parity.
+
+// Parity is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Compact, versioned binary codec used to move `Receipt`, `RichReceipt` and `LocalizedReceipt`
+//! between worker processes and caches.
+//!
+//! This is independent of the consensus RLP encoding, which deliberately omits fields (such as
+//! `transaction_hash`) that only make sense off the wire between trusted local processes. Byte
+//! strings and lists carry a length prefix and every other field has a fixed width, so a reader
+//! never has to guess a field's size, and every
+//! top-level value starts with a version byte so the format can evolve without breaking callers
+//! that haven't upgraded yet.
+
+use std::{fmt, mem};
+
+use bigint::prelude::U256;
+use bigint::hash::H256;
+use util::Address;
+use ethbloom::Bloom;
+
+use {BlockNumber};
+use log_entry::{LogEntry, LocalizedLogEntry};
+use receipt::{Receipt, RichReceipt, LocalizedReceipt, TransactionOutcome, TypedReceipt, TypedTxId};
+
+/// Version of the binary format produced by `to_binary`. Bumped whenever the wire layout of one
+/// of the types in this module changes in a way older readers can't cope with.
+pub const BINARY_VERSION: u8 = 1;
+
+/// Receipt-type tag in the binary encoding, mirroring `receipt::TypedTxId` so a typed receipt
+/// can be told apart from a legacy one without a format bump.
+const TYPE_TAG_LEGACY: u8 = 0;
+const TYPE_TAG_ACCESS_LIST: u8 = TypedTxId::AccessList as u8;
+const TYPE_TAG_EIP1559: u8 = TypedTxId::Eip1559 as u8;
+
+/// Error produced while decoding the binary format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BinaryError {
+	/// The buffer ended before a complete value could be read.
+	UnexpectedEof,
+	/// The leading version byte isn't one this build understands.
+	UnknownVersion(u8),
+	/// The `TransactionOutcome` discriminant byte wasn't 0, 1 or 2.
+	UnknownOutcomeTag(u8),
+	/// The receipt type tag wasn't one this build understands.
+	UnknownTypeTag(u8),
+	/// Trailing bytes were left over after decoding a value that should have consumed the
+	/// whole buffer.
+	TrailingBytes,
+}
+
+impl fmt::Display for BinaryError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match *self {
+			BinaryError::UnexpectedEof => write!(f, "unexpected end of buffer"),
+			BinaryError::UnknownVersion(v) => write!(f, "unknown binary format version: {}", v),
+			BinaryError::UnknownOutcomeTag(t) => write!(f, "unknown transaction outcome tag: {}", t),
+			BinaryError::UnknownTypeTag(t) => write!(f, "unknown receipt type tag: {}", t),
+			BinaryError::TrailingBytes => write!(f, "trailing bytes after decoded value"),
+		}
+	}
+}
+
+impl ::std::error::Error for BinaryError {
+	fn description(&self) -> &str {
+		"failed to decode IPC binary data"
+	}
+}
+
+/// A type with a compact binary wire format, used for cross-process IPC.
+pub trait BinaryConvertable: Sized {
+	/// Append the binary encoding of `self` to `buffer`.
+	fn to_binary(&self, buffer: &mut Vec<u8>);
+
+	/// Decode a value from the front of `buffer`, returning it along with the number of bytes
+	/// consumed so the caller can keep decoding the rest of the buffer.
+	fn from_binary(buffer: &[u8]) -> Result<(Self, usize), BinaryError>;
+
+	/// Encode `self` into a fresh, owned buffer.
+	fn to_binary_vec(&self) -> Vec<u8> {
+		let mut buffer = Vec::new();
+		self.to_binary(&mut buffer);
+		buffer
+	}
+
+	/// Decode a value that is expected to fill the whole buffer exactly.
+	fn from_binary_vec(buffer: &[u8]) -> Result<Self, BinaryError> {
+		let (value, consumed) = Self::from_binary(buffer)?;
+		if consumed != buffer.len() {
+			return Err(BinaryError::TrailingBytes);
+		}
+		Ok(value)
+	}
+}
+
+fn take(buffer: &[u8], len: usize) -> Result<&[u8], BinaryError> {
+	if buffer.len() < len {
+		return Err(BinaryError::UnexpectedEof);
+	}
+	Ok(&buffer[..len])
+}
+
+fn write_u32(buffer: &mut Vec<u8>, value: u32) {
+	for i in 0..mem::size_of::<u32>() {
+		buffer.push((value >> (i * 8)) as u8);
+	}
+}
+
+fn read_u32(buffer: &[u8]) -> Result<(u32, usize), BinaryError> {
+	let bytes = take(buffer, mem::size_of::<u32>())?;
+	let value = bytes.iter().enumerate().fold(0u32, |acc, (i, &b)| acc | ((b as u32) << (i * 8)));
+	Ok((value, 4))
+}
+
+fn write_u64(buffer: &mut Vec<u8>, value: u64) {
+	for i in 0..mem::size_of::<u64>() {
+		buffer.push((value >> (i * 8)) as u8);
+	}
+}
+
+fn read_u64(buffer: &[u8]) -> Result<(u64, usize), BinaryError> {
+	let bytes = take(buffer, mem::size_of::<u64>())?;
+	let value = bytes.iter().enumerate().fold(0u64, |acc, (i, &b)| acc | ((b as u64) << (i * 8)));
+	Ok((value, 8))
+}
+
+fn write_fixed(buffer: &mut Vec<u8>, bytes: &[u8]) {
+	buffer.extend_from_slice(bytes);
+}
+
+fn write_bytes(buffer: &mut Vec<u8>, bytes: &[u8]) {
+	write_u32(buffer, bytes.len() as u32);
+	buffer.extend_from_slice(bytes);
+}
+
+fn read_bytes(buffer: &[u8]) -> Result<(Vec<u8>, usize), BinaryError> {
+	let (len, mut consumed) = read_u32(buffer)?;
+	let len = len as usize;
+	let data = take(&buffer[consumed..], len)?;
+	let value = data.to_vec();
+	consumed += len;
+	Ok((value, consumed))
+}
+
+fn write_vec<T: BinaryConvertable>(buffer: &mut Vec<u8>, items: &[T]) {
+	write_u32(buffer, items.len() as u32);
+	for item in items {
+		item.to_binary(buffer);
+	}
+}
+
+fn read_vec<T: BinaryConvertable>(buffer: &[u8]) -> Result<(Vec<T>, usize), BinaryError> {
+	let (count, mut consumed) = read_u32(buffer)?;
+	// Don't pre-allocate based on the untrusted count: a corrupted or truncated buffer could
+	// claim billions of items and abort the process before a single one is decoded. Let the
+	// per-item `from_binary` calls fail fast with `UnexpectedEof` instead.
+	let mut items = Vec::new();
+	for _ in 0..count {
+		let (item, item_len) = T::from_binary(&buffer[consumed..])?;
+		items.push(item);
+		consumed += item_len;
+	}
+	Ok((items, consumed))
+}
+
+impl BinaryConvertable for u8 {
+	fn to_binary(&self, buffer: &mut Vec<u8>) {
+		buffer.push(*self);
+	}
+
+	fn from_binary(buffer: &[u8]) -> Result<(Self, usize), BinaryError> {
+		let bytes = take(buffer, 1)?;
+		Ok((bytes[0], 1))
+	}
+}
+
+impl BinaryConvertable for U256 {
+	fn to_binary(&self, buffer: &mut Vec<u8>) {
+		let mut bytes = [0u8; 32];
+		self.to_big_endian(&mut bytes);
+		write_fixed(buffer, &bytes);
+	}
+
+	fn from_binary(buffer: &[u8]) -> Result<(Self, usize), BinaryError> {
+		let bytes = take(buffer, 32)?;
+		Ok((U256::from_big_endian(bytes), 32))
+	}
+}
+
+impl BinaryConvertable for H256 {
+	fn to_binary(&self, buffer: &mut Vec<u8>) {
+		write_fixed(buffer, self.as_ref());
+	}
+
+	fn from_binary(buffer: &[u8]) -> Result<(Self, usize), BinaryError> {
+		let bytes = take(buffer, 32)?;
+		Ok((H256::from_slice(bytes), 32))
+	}
+}
+
+impl BinaryConvertable for Address {
+	fn to_binary(&self, buffer: &mut Vec<u8>) {
+		write_fixed(buffer, self.as_ref());
+	}
+
+	fn from_binary(buffer: &[u8]) -> Result<(Self, usize), BinaryError> {
+		let bytes = take(buffer, 20)?;
+		Ok((Address::from_slice(bytes), 20))
+	}
+}
+
+impl BinaryConvertable for Bloom {
+	fn to_binary(&self, buffer: &mut Vec<u8>) {
+		write_fixed(buffer, self.as_ref());
+	}
+
+	fn from_binary(buffer: &[u8]) -> Result<(Self, usize), BinaryError> {
+		let bytes = take(buffer, 256)?;
+		let mut array = [0u8; 256];
+		array.copy_from_slice(bytes);
+		Ok((Bloom::from(array), 256))
+	}
+}
+
+impl<T: BinaryConvertable> BinaryConvertable for Option<T> {
+	fn to_binary(&self, buffer: &mut Vec<u8>) {
+		match *self {
+			None => buffer.push(0),
+			Some(ref value) => {
+				buffer.push(1);
+				value.to_binary(buffer);
+			}
+		}
+	}
+
+	fn from_binary(buffer: &[u8]) -> Result<(Self, usize), BinaryError> {
+		let tag = take(buffer, 1)?[0];
+		match tag {
+			0 => Ok((None, 1)),
+			_ => {
+				let (value, len) = T::from_binary(&buffer[1..])?;
+				Ok((Some(value), 1 + len))
+			}
+		}
+	}
+}
+
+/// Discriminant byte for `TransactionOutcome`, kept independent of the RLP encoding.
+const OUTCOME_UNKNOWN: u8 = 0;
+const OUTCOME_STATE_ROOT: u8 = 1;
+const OUTCOME_STATUS_CODE: u8 = 2;
+
+impl BinaryConvertable for TransactionOutcome {
+	fn to_binary(&self, buffer: &mut Vec<u8>) {
+		match *self {
+			TransactionOutcome::Unknown => buffer.push(OUTCOME_UNKNOWN),
+			TransactionOutcome::StateRoot(ref root) => {
+				buffer.push(OUTCOME_STATE_ROOT);
+				root.to_binary(buffer);
+			},
+			TransactionOutcome::StatusCode(code) => {
+				buffer.push(OUTCOME_STATUS_CODE);
+				code.to_binary(buffer);
+			},
+		}
+	}
+
+	fn from_binary(buffer: &[u8]) -> Result<(Self, usize), BinaryError> {
+		let tag = take(buffer, 1)?[0];
+		match tag {
+			OUTCOME_UNKNOWN => Ok((TransactionOutcome::Unknown, 1)),
+			OUTCOME_STATE_ROOT => {
+				let (root, len) = H256::from_binary(&buffer[1..])?;
+				Ok((TransactionOutcome::StateRoot(root), 1 + len))
+			},
+			OUTCOME_STATUS_CODE => {
+				let (code, len) = u8::from_binary(&buffer[1..])?;
+				Ok((TransactionOutcome::StatusCode(code), 1 + len))
+			},
+			other => Err(BinaryError::UnknownOutcomeTag(other)),
+		}
+	}
+}
+
+impl BinaryConvertable for LogEntry {
+	fn to_binary(&self, buffer: &mut Vec<u8>) {
+		self.address.to_binary(buffer);
+		write_vec(buffer, &self.topics);
+		write_bytes(buffer, &self.data);
+	}
+
+	fn from_binary(buffer: &[u8]) -> Result<(Self, usize), BinaryError> {
+		let (address, mut consumed) = Address::from_binary(buffer)?;
+		let (topics, len) = read_vec(&buffer[consumed..])?;
+		consumed += len;
+		let (data, len) = read_bytes(&buffer[consumed..])?;
+		consumed += len;
+		Ok((LogEntry { address: address, topics: topics, data: data }, consumed))
+	}
+}
+
+impl BinaryConvertable for LocalizedLogEntry {
+	fn to_binary(&self, buffer: &mut Vec<u8>) {
+		self.entry.to_binary(buffer);
+		self.block_hash.to_binary(buffer);
+		write_u64(buffer, self.block_number as u64);
+		write_u32(buffer, self.transaction_index as u32);
+		self.transaction_hash.to_binary(buffer);
+		write_u32(buffer, self.transaction_log_index as u32);
+		write_u32(buffer, self.log_index as u32);
+	}
+
+	fn from_binary(buffer: &[u8]) -> Result<(Self, usize), BinaryError> {
+		let (entry, mut consumed) = LogEntry::from_binary(buffer)?;
+		let (block_hash, len) = H256::from_binary(&buffer[consumed..])?;
+		consumed += len;
+		let (block_number, len) = read_u64(&buffer[consumed..])?;
+		consumed += len;
+		let (transaction_index, len) = read_u32(&buffer[consumed..])?;
+		consumed += len;
+		let (transaction_hash, len) = H256::from_binary(&buffer[consumed..])?;
+		consumed += len;
+		let (transaction_log_index, len) = read_u32(&buffer[consumed..])?;
+		consumed += len;
+		let (log_index, len) = read_u32(&buffer[consumed..])?;
+		consumed += len;
+		Ok((LocalizedLogEntry {
+			entry: entry,
+			block_hash: block_hash,
+			block_number: block_number as BlockNumber,
+			transaction_index: transaction_index as usize,
+			transaction_hash: transaction_hash,
+			transaction_log_index: transaction_log_index as usize,
+			log_index: log_index as usize,
+		}, consumed))
+	}
+}
+
+fn write_header(buffer: &mut Vec<u8>, type_tag: u8) {
+	buffer.push(BINARY_VERSION);
+	buffer.push(type_tag);
+}
+
+/// Read the version byte and return the receipt-type tag alongside the number of header bytes
+/// consumed. Callers decide which type tags they accept.
+fn read_header(buffer: &[u8]) -> Result<(u8, usize), BinaryError> {
+	let version = take(buffer, 1)?[0];
+	if version != BINARY_VERSION {
+		return Err(BinaryError::UnknownVersion(version));
+	}
+	let type_tag = take(&buffer[1..], 1)?[0];
+	Ok((type_tag, 2))
+}
+
+fn write_receipt_body(receipt: &Receipt, buffer: &mut Vec<u8>) {
+	receipt.outcome.to_binary(buffer);
+	receipt.gas_used.to_binary(buffer);
+	receipt.log_bloom.to_binary(buffer);
+	write_vec(buffer, &receipt.logs);
+}
+
+fn read_receipt_body(buffer: &[u8]) -> Result<(Receipt, usize), BinaryError> {
+	let (outcome, mut consumed) = TransactionOutcome::from_binary(buffer)?;
+	let (gas_used, len) = U256::from_binary(&buffer[consumed..])?;
+	consumed += len;
+	let (log_bloom, len) = Bloom::from_binary(&buffer[consumed..])?;
+	consumed += len;
+	let (logs, len) = read_vec(&buffer[consumed..])?;
+	consumed += len;
+	Ok((Receipt { gas_used: gas_used, log_bloom: log_bloom, logs: logs, outcome: outcome }, consumed))
+}
+
+impl BinaryConvertable for Receipt {
+	fn to_binary(&self, buffer: &mut Vec<u8>) {
+		write_header(buffer, TYPE_TAG_LEGACY);
+		write_receipt_body(self, buffer);
+	}
+
+	fn from_binary(buffer: &[u8]) -> Result<(Self, usize), BinaryError> {
+		let (type_tag, header_len) = read_header(buffer)?;
+		if type_tag != TYPE_TAG_LEGACY {
+			return Err(BinaryError::UnknownTypeTag(type_tag));
+		}
+		let (receipt, body_len) = read_receipt_body(&buffer[header_len..])?;
+		Ok((receipt, header_len + body_len))
+	}
+}
+
+impl BinaryConvertable for TypedReceipt {
+	fn to_binary(&self, buffer: &mut Vec<u8>) {
+		let type_tag = match self.type_id() {
+			None => TYPE_TAG_LEGACY,
+			Some(TypedTxId::AccessList) => TYPE_TAG_ACCESS_LIST,
+			Some(TypedTxId::Eip1559) => TYPE_TAG_EIP1559,
+		};
+		write_header(buffer, type_tag);
+		write_receipt_body(self.receipt(), buffer);
+	}
+
+	fn from_binary(buffer: &[u8]) -> Result<(Self, usize), BinaryError> {
+		let (type_tag, header_len) = read_header(buffer)?;
+		let (receipt, body_len) = read_receipt_body(&buffer[header_len..])?;
+		let consumed = header_len + body_len;
+		match type_tag {
+			TYPE_TAG_LEGACY => Ok((TypedReceipt::Legacy(receipt), consumed)),
+			TYPE_TAG_ACCESS_LIST => Ok((TypedReceipt::AccessList(receipt), consumed)),
+			TYPE_TAG_EIP1559 => Ok((TypedReceipt::Eip1559(receipt), consumed)),
+			other => Err(BinaryError::UnknownTypeTag(other)),
+		}
+	}
+}
+
+impl BinaryConvertable for RichReceipt {
+	fn to_binary(&self, buffer: &mut Vec<u8>) {
+		write_header(buffer, TYPE_TAG_LEGACY);
+		self.transaction_hash.to_binary(buffer);
+		write_u32(buffer, self.transaction_index as u32);
+		self.from.to_binary(buffer);
+		self.to.to_binary(buffer);
+		self.transaction_type.to_binary(buffer);
+		self.effective_gas_price.to_binary(buffer);
+		self.cumulative_gas_used.to_binary(buffer);
+		self.gas_used.to_binary(buffer);
+		self.contract_address.to_binary(buffer);
+		write_vec(buffer, &self.logs);
+		self.log_bloom.to_binary(buffer);
+		self.outcome.to_binary(buffer);
+	}
+
+	fn from_binary(buffer: &[u8]) -> Result<(Self, usize), BinaryError> {
+		let (type_tag, mut consumed) = read_header(buffer)?;
+		if type_tag != TYPE_TAG_LEGACY {
+			return Err(BinaryError::UnknownTypeTag(type_tag));
+		}
+		let (transaction_hash, len) = H256::from_binary(&buffer[consumed..])?;
+		consumed += len;
+		let (transaction_index, len) = read_u32(&buffer[consumed..])?;
+		consumed += len;
+		let (from, len) = Address::from_binary(&buffer[consumed..])?;
+		consumed += len;
+		let (to, len) = Option::<Address>::from_binary(&buffer[consumed..])?;
+		consumed += len;
+		let (transaction_type, len) = Option::<u8>::from_binary(&buffer[consumed..])?;
+		consumed += len;
+		let (effective_gas_price, len) = U256::from_binary(&buffer[consumed..])?;
+		consumed += len;
+		let (cumulative_gas_used, len) = U256::from_binary(&buffer[consumed..])?;
+		consumed += len;
+		let (gas_used, len) = U256::from_binary(&buffer[consumed..])?;
+		consumed += len;
+		let (contract_address, len) = Option::<Address>::from_binary(&buffer[consumed..])?;
+		consumed += len;
+		let (logs, len) = read_vec(&buffer[consumed..])?;
+		consumed += len;
+		let (log_bloom, len) = Bloom::from_binary(&buffer[consumed..])?;
+		consumed += len;
+		let (outcome, len) = TransactionOutcome::from_binary(&buffer[consumed..])?;
+		consumed += len;
+		Ok((RichReceipt {
+			transaction_hash: transaction_hash,
+			transaction_index: transaction_index as usize,
+			from: from,
+			to: to,
+			transaction_type: transaction_type,
+			effective_gas_price: effective_gas_price,
+			cumulative_gas_used: cumulative_gas_used,
+			gas_used: gas_used,
+			contract_address: contract_address,
+			logs: logs,
+			log_bloom: log_bloom,
+			outcome: outcome,
+		}, consumed))
+	}
+}
+
+impl BinaryConvertable for LocalizedReceipt {
+	fn to_binary(&self, buffer: &mut Vec<u8>) {
+		write_header(buffer, TYPE_TAG_LEGACY);
+		self.transaction_hash.to_binary(buffer);
+		write_u32(buffer, self.transaction_index as u32);
+		self.block_hash.to_binary(buffer);
+		write_u64(buffer, self.block_number as u64);
+		self.from.to_binary(buffer);
+		self.to.to_binary(buffer);
+		self.transaction_type.to_binary(buffer);
+		self.effective_gas_price.to_binary(buffer);
+		self.cumulative_gas_used.to_binary(buffer);
+		self.gas_used.to_binary(buffer);
+		self.contract_address.to_binary(buffer);
+		write_vec(buffer, &self.logs);
+		self.log_bloom.to_binary(buffer);
+		self.outcome.to_binary(buffer);
+	}
+
+	fn from_binary(buffer: &[u8]) -> Result<(Self, usize), BinaryError> {
+		let (type_tag, mut consumed) = read_header(buffer)?;
+		if type_tag != TYPE_TAG_LEGACY {
+			return Err(BinaryError::UnknownTypeTag(type_tag));
+		}
+		let (transaction_hash, len) = H256::from_binary(&buffer[consumed..])?;
+		consumed += len;
+		let (transaction_index, len) = read_u32(&buffer[consumed..])?;
+		consumed += len;
+		let (block_hash, len) = H256::from_binary(&buffer[consumed..])?;
+		consumed += len;
+		let (block_number, len) = read_u64(&buffer[consumed..])?;
+		consumed += len;
+		let (from, len) = Address::from_binary(&buffer[consumed..])?;
+		consumed += len;
+		let (to, len) = Option::<Address>::from_binary(&buffer[consumed..])?;
+		consumed += len;
+		let (transaction_type, len) = Option::<u8>::from_binary(&buffer[consumed..])?;
+		consumed += len;
+		let (effective_gas_price, len) = U256::from_binary(&buffer[consumed..])?;
+		consumed += len;
+		let (cumulative_gas_used, len) = U256::from_binary(&buffer[consumed..])?;
+		consumed += len;
+		let (gas_used, len) = U256::from_binary(&buffer[consumed..])?;
+		consumed += len;
+		let (contract_address, len) = Option::<Address>::from_binary(&buffer[consumed..])?;
+		consumed += len;
+		let (logs, len) = read_vec(&buffer[consumed..])?;
+		consumed += len;
+		let (log_bloom, len) = Bloom::from_binary(&buffer[consumed..])?;
+		consumed += len;
+		let (outcome, len) = TransactionOutcome::from_binary(&buffer[consumed..])?;
+		consumed += len;
+		Ok((LocalizedReceipt {
+			transaction_hash: transaction_hash,
+			transaction_index: transaction_index as usize,
+			block_hash: block_hash,
+			block_number: block_number as BlockNumber,
+			from: from,
+			to: to,
+			transaction_type: transaction_type,
+			effective_gas_price: effective_gas_price,
+			cumulative_gas_used: cumulative_gas_used,
+			gas_used: gas_used,
+			contract_address: contract_address,
+			logs: logs,
+			log_bloom: log_bloom,
+			outcome: outcome,
+		}, consumed))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{BinaryConvertable, Receipt};
+	use receipt::{TransactionOutcome, TypedReceipt, RichReceipt, LocalizedReceipt};
+	use log_entry::{LogEntry, LocalizedLogEntry};
+
+	#[test]
+	fn test_receipt_binary_roundtrip() {
+		let receipt = Receipt::new(
+			TransactionOutcome::StatusCode(1),
+			0x40cae.into(),
+			vec![LogEntry {
+				address: "dcf421d093428b096ca501a7cd1a740855a7976f".into(),
+				topics: vec!["0000000000000000000000000000000000000000000000000000000000000001".into()],
+				data: vec![1u8, 2, 3],
+			}]
+		);
+
+		let encoded = receipt.to_binary_vec();
+		let decoded = Receipt::from_binary_vec(&encoded).unwrap();
+		assert_eq!(decoded, receipt);
+	}
+
+	#[test]
+	fn test_receipt_binary_rejects_unknown_version() {
+		let receipt = Receipt::new(TransactionOutcome::Unknown, 0.into(), vec![]);
+		let mut encoded = receipt.to_binary_vec();
+		encoded[0] = 0xff;
+		assert!(Receipt::from_binary_vec(&encoded).is_err());
+	}
+
+	fn sample_receipt() -> Receipt {
+		Receipt::new(
+			TransactionOutcome::StatusCode(1),
+			0x40cae.into(),
+			vec![LogEntry {
+				address: "dcf421d093428b096ca501a7cd1a740855a7976f".into(),
+				topics: vec![],
+				data: vec![0u8; 32],
+			}]
+		)
+	}
+
+	#[test]
+	fn test_typed_receipt_binary_roundtrip() {
+		for receipt in vec![
+			TypedReceipt::Legacy(sample_receipt()),
+			TypedReceipt::AccessList(sample_receipt()),
+			TypedReceipt::Eip1559(sample_receipt()),
+		] {
+			let encoded = receipt.to_binary_vec();
+			let decoded = TypedReceipt::from_binary_vec(&encoded).unwrap();
+			assert_eq!(decoded, receipt);
+		}
+	}
+
+	#[test]
+	fn test_typed_receipt_binary_rejects_unknown_type_tag() {
+		let mut encoded = TypedReceipt::Legacy(sample_receipt()).to_binary_vec();
+		encoded[1] = 0xff;
+		assert!(TypedReceipt::from_binary_vec(&encoded).is_err());
+	}
+
+	fn sample_rich_receipt(to: Option<::util::Address>, transaction_type: Option<u8>, contract_address: Option<::util::Address>) -> RichReceipt {
+		RichReceipt {
+			transaction_hash: "0000000000000000000000000000000000000000000000000000000000000001".into(),
+			transaction_index: 0,
+			from: "dcf421d093428b096ca501a7cd1a740855a7976f".into(),
+			to: to,
+			transaction_type: transaction_type,
+			effective_gas_price: 0x1234.into(),
+			cumulative_gas_used: 0x40cae.into(),
+			gas_used: 0x100.into(),
+			contract_address: contract_address,
+			logs: vec![LogEntry {
+				address: "dcf421d093428b096ca501a7cd1a740855a7976f".into(),
+				topics: vec![],
+				data: vec![1u8, 2, 3],
+			}],
+			log_bloom: Default::default(),
+			outcome: TransactionOutcome::StatusCode(1),
+		}
+	}
+
+	#[test]
+	fn test_rich_receipt_binary_roundtrip() {
+		let receipt = sample_rich_receipt(
+			Some("dcf421d093428b096ca501a7cd1a740855a7976f".into()),
+			Some(2),
+			None,
+		);
+
+		let encoded = receipt.to_binary_vec();
+		let decoded = RichReceipt::from_binary_vec(&encoded).unwrap();
+		assert_eq!(decoded, receipt);
+	}
+
+	#[test]
+	fn test_rich_receipt_binary_roundtrip_legacy_contract_creation() {
+		let receipt = sample_rich_receipt(
+			None,
+			None,
+			Some("dcf421d093428b096ca501a7cd1a740855a7976f".into()),
+		);
+
+		let encoded = receipt.to_binary_vec();
+		let decoded = RichReceipt::from_binary_vec(&encoded).unwrap();
+		assert_eq!(decoded, receipt);
+	}
+
+	fn sample_localized_receipt(to: Option<::util::Address>, transaction_type: Option<u8>, contract_address: Option<::util::Address>) -> LocalizedReceipt {
+		LocalizedReceipt {
+			transaction_hash: "0000000000000000000000000000000000000000000000000000000000000001".into(),
+			transaction_index: 0,
+			block_hash: "0000000000000000000000000000000000000000000000000000000000000002".into(),
+			block_number: 42,
+			from: "dcf421d093428b096ca501a7cd1a740855a7976f".into(),
+			to: to,
+			transaction_type: transaction_type,
+			effective_gas_price: 0x1234.into(),
+			cumulative_gas_used: 0x40cae.into(),
+			gas_used: 0x100.into(),
+			contract_address: contract_address,
+			logs: vec![LocalizedLogEntry {
+				entry: LogEntry {
+					address: "dcf421d093428b096ca501a7cd1a740855a7976f".into(),
+					topics: vec!["0000000000000000000000000000000000000000000000000000000000000001".into()],
+					data: vec![],
+				},
+				block_hash: "0000000000000000000000000000000000000000000000000000000000000002".into(),
+				block_number: 42,
+				transaction_hash: "0000000000000000000000000000000000000000000000000000000000000001".into(),
+				transaction_index: 0,
+				transaction_log_index: 0,
+				log_index: 0,
+			}],
+			log_bloom: Default::default(),
+			outcome: TransactionOutcome::Unknown,
+		}
+	}
+
+	#[test]
+	fn test_localized_receipt_binary_roundtrip() {
+		let receipt = sample_localized_receipt(
+			Some("dcf421d093428b096ca501a7cd1a740855a7976f".into()),
+			Some(1),
+			None,
+		);
+
+		let encoded = receipt.to_binary_vec();
+		let decoded = LocalizedReceipt::from_binary_vec(&encoded).unwrap();
+		assert_eq!(decoded, receipt);
+	}
+
+	#[test]
+	fn test_localized_receipt_binary_roundtrip_legacy_contract_creation() {
+		let receipt = sample_localized_receipt(
+			None,
+			None,
+			Some("dcf421d093428b096ca501a7cd1a740855a7976f".into()),
+		);
+
+		let encoded = receipt.to_binary_vec();
+		let decoded = LocalizedReceipt::from_binary_vec(&encoded).unwrap();
+		assert_eq!(decoded, receipt);
+	}
+}
@@ -23,6 +23,10 @@ use heapsize::HeapSizeOf;
 use rlp::*;
 use ethbloom::{Bloom, BloomRef};
 
+use std::{cmp, fmt};
+
+use triehash::ordered_trie_root;
+
 use {BlockNumber};
 use log_entry::{LogEntry, LocalizedLogEntry};
 
@@ -144,6 +148,200 @@ impl HeapSizeOf for Receipt {
 	}
 }
 
+/// Error returned when a set of receipts doesn't reproduce a trusted header's receipts root
+/// or logs bloom.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReceiptsError {
+	/// The receipts trie root computed from the given receipts doesn't match the header's.
+	InvalidReceiptsRoot {
+		/// Root taken from the header.
+		expected: H256,
+		/// Root computed from the given receipts.
+		got: H256,
+	},
+	/// The logs bloom computed from the given receipts doesn't match the header's.
+	InvalidLogBloom {
+		/// Bloom taken from the header.
+		expected: Box<Bloom>,
+		/// Bloom computed from the given receipts.
+		got: Box<Bloom>,
+	},
+}
+
+impl fmt::Display for ReceiptsError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match *self {
+			ReceiptsError::InvalidReceiptsRoot { ref expected, ref got } =>
+				write!(f, "invalid receipts root: expected {}, got {}", expected, got),
+			ReceiptsError::InvalidLogBloom { .. } =>
+				write!(f, "invalid receipts logs bloom"),
+		}
+	}
+}
+
+impl ::std::error::Error for ReceiptsError {
+	fn description(&self) -> &str {
+		"receipts do not reproduce the expected root or bloom"
+	}
+}
+
+/// Compute the receipts trie root over the consensus encoding of `receipts`, in order.
+///
+/// Each leaf is `TypedReceipt::envelope`: the bare RLP list for a legacy receipt, or the
+/// EIP-2718 type byte followed by the RLP of the inner receipt list for a typed one. A root
+/// computed from the untyped `Receipt` alone would be wrong for any block containing a
+/// typed (EIP-2930/EIP-1559) transaction, since its receipt is keyed in the trie on
+/// `TransactionType || ReceiptPayload`, not on the bare list.
+///
+/// This is the `receipts_root` consensus requires in a block header.
+pub fn receipts_root(receipts: &[TypedReceipt]) -> H256 {
+	ordered_trie_root(receipts.iter().map(TypedReceipt::envelope))
+}
+
+/// Compute the block-level logs bloom as the OR-combination of every receipt's log bloom.
+///
+/// This is the `logs_bloom` consensus requires in a block header.
+pub fn receipts_log_bloom(receipts: &[TypedReceipt]) -> Bloom {
+	receipts.iter().fold(Bloom::default(), |mut bloom, receipt| {
+		bloom.accrue_bloom(BloomRef::from(&receipt.receipt().log_bloom));
+		bloom
+	})
+}
+
+/// Verify that `receipts` reproduce `expected_root` and `expected_bloom`, letting light-client
+/// and import paths validate a downloaded receipts list against a trusted header without
+/// pulling in the full block executor.
+pub fn verify_receipts(expected_root: &H256, expected_bloom: &Bloom, receipts: &[TypedReceipt]) -> Result<(), ReceiptsError> {
+	let got_root = receipts_root(receipts);
+	if &got_root != expected_root {
+		return Err(ReceiptsError::InvalidReceiptsRoot { expected: expected_root.clone(), got: got_root });
+	}
+
+	let got_bloom = receipts_log_bloom(receipts);
+	if &got_bloom != expected_bloom {
+		return Err(ReceiptsError::InvalidLogBloom {
+			expected: Box::new(expected_bloom.clone()),
+			got: Box::new(got_bloom),
+		});
+	}
+
+	Ok(())
+}
+
+/// EIP-2718 transaction type discriminant carried by a typed receipt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypedTxId {
+	/// EIP-2930 access list transaction, type `0x01`.
+	AccessList = 0x01,
+	/// EIP-1559 transaction, type `0x02`.
+	Eip1559 = 0x02,
+}
+
+/// A `Receipt` together with the EIP-2718 envelope of the transaction it belongs to.
+///
+/// Legacy receipts encode exactly as `Receipt` does: a bare RLP list. Typed receipts
+/// encode as the type byte followed by the RLP of the inner receipt list, and when
+/// embedded in another RLP structure that payload is wrapped as an RLP byte-string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypedReceipt {
+	/// Pre-EIP-2718 receipt.
+	Legacy(Receipt),
+	/// EIP-2930 access list transaction receipt.
+	AccessList(Receipt),
+	/// EIP-1559 transaction receipt.
+	Eip1559(Receipt),
+}
+
+impl TypedReceipt {
+	/// The consensus receipt wrapped by this envelope.
+	pub fn receipt(&self) -> &Receipt {
+		match *self {
+			TypedReceipt::Legacy(ref r) | TypedReceipt::AccessList(ref r) | TypedReceipt::Eip1559(ref r) => r,
+		}
+	}
+
+	/// EIP-2718 transaction type of this receipt, or `None` for a legacy receipt.
+	pub fn type_id(&self) -> Option<TypedTxId> {
+		match *self {
+			TypedReceipt::Legacy(_) => None,
+			TypedReceipt::AccessList(_) => Some(TypedTxId::AccessList),
+			TypedReceipt::Eip1559(_) => Some(TypedTxId::Eip1559),
+		}
+	}
+
+	fn encode_typed(id: TypedTxId, receipt: &Receipt, s: &mut RlpStream) {
+		let mut rlps = RlpStream::new();
+		receipt.rlp_append(&mut rlps);
+		let mut payload = vec![id as u8];
+		payload.extend_from_slice(&rlps.drain());
+		s.append(&payload);
+	}
+
+	/// The canonical consensus encoding used as a receipts-trie leaf: the bare RLP list for a
+	/// legacy receipt, or the EIP-2718 type byte followed directly by the RLP of the inner
+	/// receipt list for a typed one.
+	///
+	/// This differs from `Encodable`/`rlp_append`, which additionally wraps a typed receipt's
+	/// payload as an RLP byte-string when it is embedded inside another RLP structure (e.g. a
+	/// wire-protocol receipts list) — the trie leaf itself is not further wrapped.
+	pub fn envelope(&self) -> Vec<u8> {
+		match *self {
+			TypedReceipt::Legacy(ref r) => ::rlp::encode(r).to_vec(),
+			TypedReceipt::AccessList(ref r) => Self::typed_envelope(TypedTxId::AccessList, r),
+			TypedReceipt::Eip1559(ref r) => Self::typed_envelope(TypedTxId::Eip1559, r),
+		}
+	}
+
+	fn typed_envelope(id: TypedTxId, receipt: &Receipt) -> Vec<u8> {
+		let mut payload = vec![id as u8];
+		payload.extend_from_slice(&::rlp::encode(receipt));
+		payload
+	}
+}
+
+impl Encodable for TypedReceipt {
+	fn rlp_append(&self, s: &mut RlpStream) {
+		match *self {
+			TypedReceipt::Legacy(ref r) => r.rlp_append(s),
+			TypedReceipt::AccessList(ref r) => Self::encode_typed(TypedTxId::AccessList, r, s),
+			TypedReceipt::Eip1559(ref r) => Self::encode_typed(TypedTxId::Eip1559, r, s),
+		}
+	}
+}
+
+impl Decodable for TypedReceipt {
+	fn decode(rlp: &UntrustedRlp) -> Result<Self, DecoderError> {
+		if rlp.is_list() {
+			return Ok(TypedReceipt::Legacy(rlp.as_val()?));
+		}
+
+		let data = rlp.data()?;
+		let (&id, payload) = data.split_first().ok_or(DecoderError::Custom("Empty typed receipt"))?;
+		let inner = UntrustedRlp::new(payload).as_val()?;
+		match id {
+			x if x == TypedTxId::AccessList as u8 => Ok(TypedReceipt::AccessList(inner)),
+			x if x == TypedTxId::Eip1559 as u8 => Ok(TypedReceipt::Eip1559(inner)),
+			_ => Err(DecoderError::Custom("Unknown transaction type for receipt")),
+		}
+	}
+}
+
+/// Computes the effective gas price paid per unit of gas by a transaction included in a block.
+///
+/// For legacy and EIP-2930 transactions this is simply the transaction's gas price (`gas_price`
+/// doubling as `max_fee_per_gas` for a 1559 transaction). For an EIP-1559 transaction it is
+/// `base_fee + min(max_priority_fee_per_gas, max_fee_per_gas - base_fee)`.
+fn effective_gas_price(transaction_type: Option<TypedTxId>, gas_price: U256, max_priority_fee_per_gas: Option<U256>, base_fee: Option<U256>) -> U256 {
+	match (transaction_type, base_fee) {
+		(Some(TypedTxId::Eip1559), Some(base_fee)) => {
+			let max_fee = gas_price;
+			let priority_fee = max_priority_fee_per_gas.unwrap_or(max_fee);
+			base_fee + cmp::min(priority_fee, max_fee.saturating_sub(base_fee))
+		},
+		_ => gas_price,
+	}
+}
+
 /// Receipt with additional info.
 #[derive(Debug, Clone, PartialEq)]
 pub struct RichReceipt {
@@ -151,6 +349,14 @@ pub struct RichReceipt {
 	pub transaction_hash: H256,
 	/// Transaction index.
 	pub transaction_index: usize,
+	/// Sender of the transaction.
+	pub from: Address,
+	/// Recipient of the transaction, `None` for contract creation.
+	pub to: Option<Address>,
+	/// EIP-2718 transaction type, `None` for a legacy transaction.
+	pub transaction_type: Option<u8>,
+	/// The gas price actually paid per unit of gas by this transaction, base fee included.
+	pub effective_gas_price: U256,
 	/// The total gas used in the block following execution of the transaction.
 	pub cumulative_gas_used: U256,
 	/// The gas used in the execution of the transaction. Note the difference of meaning to `Receipt::gas_used`.
@@ -165,6 +371,63 @@ pub struct RichReceipt {
 	pub outcome: TransactionOutcome,
 }
 
+/// Inputs for building a `RichReceipt`, grouped into a named struct rather than a positional
+/// parameter list: several fields share a type (`max_priority_fee_per_gas`/`base_fee`,
+/// `cumulative_gas_used`/`gas_used`, `to`/`contract_address`), so a transposed pair would
+/// otherwise compile silently and corrupt the effective-gas-price math or recipient data.
+pub struct RichReceiptInput {
+	/// Sender of the transaction.
+	pub from: Address,
+	/// Recipient of the transaction, `None` for contract creation.
+	pub to: Option<Address>,
+	/// EIP-2718 transaction type, `None` for a legacy transaction.
+	pub transaction_type: Option<TypedTxId>,
+	/// The transaction's gas price (`max_fee_per_gas` for an EIP-1559 transaction).
+	pub gas_price: U256,
+	/// The transaction's `max_priority_fee_per_gas`, `None` unless EIP-1559.
+	pub max_priority_fee_per_gas: Option<U256>,
+	/// The block's base fee, `None` pre-EIP-1559.
+	pub base_fee: Option<U256>,
+	/// Transaction hash.
+	pub transaction_hash: H256,
+	/// Transaction index.
+	pub transaction_index: usize,
+	/// The total gas used in the block following execution of the transaction.
+	pub cumulative_gas_used: U256,
+	/// The gas used in the execution of the transaction. Note the difference of meaning to `Receipt::gas_used`.
+	pub gas_used: U256,
+	/// Contract address.
+	pub contract_address: Option<Address>,
+	/// Logs
+	pub logs: Vec<LogEntry>,
+	/// Logs bloom
+	pub log_bloom: Bloom,
+	/// Transaction outcome.
+	pub outcome: TransactionOutcome,
+}
+
+impl RichReceipt {
+	/// Build a `RichReceipt` from `input`, deriving `transaction_type` and `effective_gas_price`
+	/// from the executed transaction's type/gas fields and the block's base fee, so callers no
+	/// longer have to re-derive them from the transaction themselves.
+	pub fn new(input: RichReceiptInput) -> RichReceipt {
+		RichReceipt {
+			transaction_hash: input.transaction_hash,
+			transaction_index: input.transaction_index,
+			from: input.from,
+			to: input.to,
+			transaction_type: input.transaction_type.map(|id| id as u8),
+			effective_gas_price: effective_gas_price(input.transaction_type, input.gas_price, input.max_priority_fee_per_gas, input.base_fee),
+			cumulative_gas_used: input.cumulative_gas_used,
+			gas_used: input.gas_used,
+			contract_address: input.contract_address,
+			logs: input.logs,
+			log_bloom: input.log_bloom,
+			outcome: input.outcome,
+		}
+	}
+}
+
 /// Receipt with additional info.
 #[derive(Debug, Clone, PartialEq)]
 pub struct LocalizedReceipt {
@@ -176,6 +439,14 @@ pub struct LocalizedReceipt {
 	pub block_hash: H256,
 	/// Block number.
 	pub block_number: BlockNumber,
+	/// Sender of the transaction.
+	pub from: Address,
+	/// Recipient of the transaction, `None` for contract creation.
+	pub to: Option<Address>,
+	/// EIP-2718 transaction type, `None` for a legacy transaction.
+	pub transaction_type: Option<u8>,
+	/// The gas price actually paid per unit of gas by this transaction, base fee included.
+	pub effective_gas_price: U256,
 	/// The total gas used in the block following execution of the transaction.
 	pub cumulative_gas_used: U256,
 	/// The gas used in the execution of the transaction. Note the difference of meaning to `Receipt::gas_used`.
@@ -190,10 +461,79 @@ pub struct LocalizedReceipt {
 	pub outcome: TransactionOutcome,
 }
 
+/// Inputs for building a `LocalizedReceipt`, grouped into a named struct rather than a
+/// positional parameter list: several fields share a type (`max_priority_fee_per_gas`/
+/// `base_fee`, `cumulative_gas_used`/`gas_used`, `to`/`contract_address`), so a transposed pair
+/// would otherwise compile silently and corrupt the effective-gas-price math or recipient data.
+pub struct LocalizedReceiptInput {
+	/// Sender of the transaction.
+	pub from: Address,
+	/// Recipient of the transaction, `None` for contract creation.
+	pub to: Option<Address>,
+	/// EIP-2718 transaction type, `None` for a legacy transaction.
+	pub transaction_type: Option<TypedTxId>,
+	/// The transaction's gas price (`max_fee_per_gas` for an EIP-1559 transaction).
+	pub gas_price: U256,
+	/// The transaction's `max_priority_fee_per_gas`, `None` unless EIP-1559.
+	pub max_priority_fee_per_gas: Option<U256>,
+	/// The block's base fee, `None` pre-EIP-1559.
+	pub base_fee: Option<U256>,
+	/// Transaction hash.
+	pub transaction_hash: H256,
+	/// Transaction index.
+	pub transaction_index: usize,
+	/// Block hash.
+	pub block_hash: H256,
+	/// Block number.
+	pub block_number: BlockNumber,
+	/// The total gas used in the block following execution of the transaction.
+	pub cumulative_gas_used: U256,
+	/// The gas used in the execution of the transaction. Note the difference of meaning to `Receipt::gas_used`.
+	pub gas_used: U256,
+	/// Contract address.
+	pub contract_address: Option<Address>,
+	/// Logs
+	pub logs: Vec<LocalizedLogEntry>,
+	/// Logs bloom
+	pub log_bloom: Bloom,
+	/// Transaction outcome.
+	pub outcome: TransactionOutcome,
+}
+
+impl LocalizedReceipt {
+	/// Build a `LocalizedReceipt` from `input`, deriving `transaction_type` and
+	/// `effective_gas_price` from the executed transaction's type/gas fields and the block's
+	/// base fee, so callers no longer have to re-derive them from the transaction themselves.
+	pub fn new(input: LocalizedReceiptInput) -> LocalizedReceipt {
+		LocalizedReceipt {
+			transaction_hash: input.transaction_hash,
+			transaction_index: input.transaction_index,
+			block_hash: input.block_hash,
+			block_number: input.block_number,
+			from: input.from,
+			to: input.to,
+			transaction_type: input.transaction_type.map(|id| id as u8),
+			effective_gas_price: effective_gas_price(input.transaction_type, input.gas_price, input.max_priority_fee_per_gas, input.base_fee),
+			cumulative_gas_used: input.cumulative_gas_used,
+			gas_used: input.gas_used,
+			contract_address: input.contract_address,
+			logs: input.logs,
+			log_bloom: input.log_bloom,
+			outcome: input.outcome,
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
-	use super::{Receipt, TransactionOutcome};
+	use super::{
+		Receipt, RichReceipt, RichReceiptInput, LocalizedReceipt, LocalizedReceiptInput,
+		TransactionOutcome, TypedReceipt, TypedTxId,
+		effective_gas_price, receipts_root, receipts_log_bloom, verify_receipts,
+	};
 	use log_entry::LogEntry;
+	use util::Address;
+	use ethbloom::Bloom;
 
 	#[test]
 	fn test_no_state_root() {
@@ -245,4 +585,194 @@ mod tests {
 		let decoded: Receipt = ::rlp::decode(&encoded);
 		assert_eq!(decoded, r);
 	}
+
+	fn typed_receipt_roundtrip(r: TypedReceipt) {
+		let encoded = ::rlp::encode(&r);
+		let decoded: TypedReceipt = ::rlp::decode(&encoded);
+		assert_eq!(decoded, r);
+	}
+
+	#[test]
+	fn test_typed_receipt_legacy() {
+		let r = Receipt::new(
+			TransactionOutcome::StatusCode(1),
+			0x40cae.into(),
+			vec![LogEntry {
+				address: "dcf421d093428b096ca501a7cd1a740855a7976f".into(),
+				topics: vec![],
+				data: vec![0u8; 32]
+			}]
+		);
+		typed_receipt_roundtrip(TypedReceipt::Legacy(r));
+	}
+
+	#[test]
+	fn test_typed_receipt_access_list() {
+		let r = Receipt::new(
+			TransactionOutcome::StatusCode(1),
+			0x40cae.into(),
+			vec![LogEntry {
+				address: "dcf421d093428b096ca501a7cd1a740855a7976f".into(),
+				topics: vec![],
+				data: vec![0u8; 32]
+			}]
+		);
+		typed_receipt_roundtrip(TypedReceipt::AccessList(r));
+	}
+
+	#[test]
+	fn test_typed_receipt_eip1559() {
+		let r = Receipt::new(
+			TransactionOutcome::StatusCode(0),
+			0x40cae.into(),
+			vec![LogEntry {
+				address: "dcf421d093428b096ca501a7cd1a740855a7976f".into(),
+				topics: vec![],
+				data: vec![0u8; 32]
+			}]
+		);
+		typed_receipt_roundtrip(TypedReceipt::Eip1559(r));
+	}
+
+	#[test]
+	fn test_verify_receipts_roundtrip() {
+		let receipts = vec![
+			TypedReceipt::Legacy(Receipt::new(
+				TransactionOutcome::StatusCode(1),
+				0x40cae.into(),
+				vec![LogEntry {
+					address: "dcf421d093428b096ca501a7cd1a740855a7976f".into(),
+					topics: vec![],
+					data: vec![0u8; 32]
+				}]
+			)),
+			TypedReceipt::Eip1559(Receipt::new(
+				TransactionOutcome::StatusCode(0),
+				0x186a0.into(),
+				vec![]
+			)),
+		];
+
+		let root = receipts_root(&receipts);
+		let bloom = receipts_log_bloom(&receipts);
+		assert!(verify_receipts(&root, &bloom, &receipts).is_ok());
+	}
+
+	#[test]
+	fn test_verify_receipts_rejects_mismatch() {
+		let receipts = vec![
+			TypedReceipt::Legacy(Receipt::new(TransactionOutcome::StatusCode(1), 0x40cae.into(), vec![])),
+		];
+		let other = vec![
+			TypedReceipt::Legacy(Receipt::new(TransactionOutcome::StatusCode(1), 0x186a0.into(), vec![])),
+		];
+
+		let root = receipts_root(&receipts);
+		let bloom = receipts_log_bloom(&receipts);
+		assert!(verify_receipts(&root, &bloom, &other).is_err());
+	}
+
+	#[test]
+	fn test_receipts_root_differs_for_typed_vs_legacy() {
+		// Same inner `Receipt`, different EIP-2718 envelope: the trie leaf must differ, or a
+		// block mixing legacy and typed transactions would validate against the wrong root.
+		let receipt = Receipt::new(TransactionOutcome::StatusCode(1), 0x40cae.into(), vec![]);
+		let legacy_root = receipts_root(&[TypedReceipt::Legacy(receipt.clone())]);
+		let access_list_root = receipts_root(&[TypedReceipt::AccessList(receipt.clone())]);
+		let eip1559_root = receipts_root(&[TypedReceipt::Eip1559(receipt)]);
+
+		assert_ne!(legacy_root, access_list_root);
+		assert_ne!(legacy_root, eip1559_root);
+		assert_ne!(access_list_root, eip1559_root);
+	}
+
+	#[test]
+	fn test_effective_gas_price_legacy() {
+		// Without a base fee, or for a non-EIP-1559 type, the gas price is paid in full.
+		assert_eq!(effective_gas_price(None, 100.into(), None, None), 100.into());
+		assert_eq!(effective_gas_price(Some(TypedTxId::AccessList), 100.into(), None, Some(10.into())), 100.into());
+	}
+
+	#[test]
+	fn test_effective_gas_price_eip1559_priority_fee_below_headroom() {
+		// base_fee + priority_fee, since priority_fee <= max_fee - base_fee.
+		let price = effective_gas_price(Some(TypedTxId::Eip1559), 100.into(), Some(20.into()), Some(50.into()));
+		assert_eq!(price, 70.into());
+	}
+
+	#[test]
+	fn test_effective_gas_price_eip1559_priority_fee_clamped_to_max_fee() {
+		// priority_fee exceeds max_fee - base_fee, so it's clamped: effective price is max_fee.
+		let price = effective_gas_price(Some(TypedTxId::Eip1559), 100.into(), Some(80.into()), Some(50.into()));
+		assert_eq!(price, 100.into());
+	}
+
+	#[test]
+	fn test_rich_receipt_new_field_ordering() {
+		let from: Address = "dcf421d093428b096ca501a7cd1a740855a7976f".into();
+		let to: Address = "0000000000000000000000000000000000000001".into();
+		let contract_address: Address = "0000000000000000000000000000000000000002".into();
+
+		let receipt = RichReceipt::new(RichReceiptInput {
+			from: from,
+			to: Some(to),
+			transaction_type: Some(TypedTxId::Eip1559),
+			gas_price: 100.into(),
+			max_priority_fee_per_gas: Some(20.into()),
+			base_fee: Some(50.into()),
+			transaction_hash: "0000000000000000000000000000000000000000000000000000000000000001".into(),
+			transaction_index: 3,
+			cumulative_gas_used: 0x40cae.into(),
+			gas_used: 0x1234.into(),
+			contract_address: Some(contract_address),
+			logs: vec![],
+			log_bloom: Bloom::default(),
+			outcome: TransactionOutcome::StatusCode(1),
+		});
+
+		// Distinct `U256`/`Option<Address>` values in each slot catch a transposed field.
+		assert_eq!(receipt.from, from);
+		assert_eq!(receipt.to, Some(to));
+		assert_eq!(receipt.transaction_type, Some(TypedTxId::Eip1559 as u8));
+		assert_eq!(receipt.effective_gas_price, 70.into());
+		assert_eq!(receipt.cumulative_gas_used, 0x40cae.into());
+		assert_eq!(receipt.gas_used, 0x1234.into());
+		assert_eq!(receipt.contract_address, Some(contract_address));
+	}
+
+	#[test]
+	fn test_localized_receipt_new_field_ordering() {
+		let from: Address = "dcf421d093428b096ca501a7cd1a740855a7976f".into();
+		let to: Address = "0000000000000000000000000000000000000001".into();
+		let contract_address: Address = "0000000000000000000000000000000000000002".into();
+
+		let receipt = LocalizedReceipt::new(LocalizedReceiptInput {
+			from: from,
+			to: Some(to),
+			transaction_type: Some(TypedTxId::Eip1559),
+			gas_price: 100.into(),
+			max_priority_fee_per_gas: Some(20.into()),
+			base_fee: Some(50.into()),
+			transaction_hash: "0000000000000000000000000000000000000000000000000000000000000001".into(),
+			transaction_index: 3,
+			block_hash: "0000000000000000000000000000000000000000000000000000000000000002".into(),
+			block_number: 42,
+			cumulative_gas_used: 0x40cae.into(),
+			gas_used: 0x1234.into(),
+			contract_address: Some(contract_address),
+			logs: vec![],
+			log_bloom: Bloom::default(),
+			outcome: TransactionOutcome::StatusCode(1),
+		});
+
+		// Distinct `U256`/`Option<Address>` values in each slot catch a transposed field.
+		assert_eq!(receipt.from, from);
+		assert_eq!(receipt.to, Some(to));
+		assert_eq!(receipt.block_number, 42);
+		assert_eq!(receipt.transaction_type, Some(TypedTxId::Eip1559 as u8));
+		assert_eq!(receipt.effective_gas_price, 70.into());
+		assert_eq!(receipt.cumulative_gas_used, 0x40cae.into());
+		assert_eq!(receipt.gas_used, 0x1234.into());
+		assert_eq!(receipt.contract_address, Some(contract_address));
+	}
 }